@@ -4,7 +4,7 @@
 //! By mimicking the naming conventions from `std::time::Duration`, it aims to provide an intuitive way to create durations.
 //!
 //! # Examples
-//! 
+//!
 //! ```rust
 //! use duration_helper::DurationHelper;
 //!
@@ -18,16 +18,40 @@
 //! - Intuitive naming aligned with `std::time::Duration`.
 //! - Support for a wide range of time units, from nanoseconds to years.
 //! - Both integer and floating point number types are supported.
+//! - `DurationHelper` is sealed, so it can only be implemented by this crate for the primitive numeric types it already covers.
+//! - [`SignedDurationHelper`] and [`SignedDuration`] cover the same units for callers who need negative durations.
+//! - [`parse_duration`] and [`humanize`] round-trip the same unit vocabulary to and from strings, e.g. for config files.
 //!
 //! Note: This library makes some assumptions, particularly for larger durations. For example, a month is considered as 30 days and a year is considered as 365 days.
 
 use std::time::Duration;
 
-pub trait DurationHelper {
+mod signed;
+pub use signed::{SignedDuration, SignedDurationHelper, TryFromSignedDurationError};
+
+mod parse;
+pub use parse::{humanize, parse_duration, ParseError};
+
+mod sealed {
+    //! Mirrors the `time` crate's `ext.rs` sealed-trait pattern: keeps `DurationHelper`
+    //! closed to implementations outside this crate.
+    pub trait Sealed {}
+
+    macro_rules! impl_sealed {
+        ($($t:ty),* $(,)?) => {
+            $(impl Sealed for $t {})*
+        };
+    }
+
+    impl_sealed!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, f32, f64);
+}
+
+pub trait DurationHelper: sealed::Sealed {
     fn nanos(self) -> Duration;
     fn micros(self) -> Duration;
     fn millis(self) -> Duration;
     fn secs(self) -> Duration;
+    fn minutes(self) -> Duration;
     fn hours(self) -> Duration;
     fn days(self) -> Duration;
     fn weeks(self) -> Duration;
@@ -35,32 +59,340 @@ pub trait DurationHelper {
     fn months(self) -> Duration;
     /// Assuming a year is 365 days
     fn years(self) -> Duration;
+
+    /// Like [`nanos`](DurationHelper::nanos), but returns `None` instead of wrapping on overflow.
+    fn checked_nanos(self) -> Option<Duration> where Self: Sized {
+        Some(self.nanos())
+    }
+    /// Like [`micros`](DurationHelper::micros), but returns `None` instead of wrapping on overflow.
+    fn checked_micros(self) -> Option<Duration> where Self: Sized {
+        Some(self.micros())
+    }
+    /// Like [`millis`](DurationHelper::millis), but returns `None` instead of wrapping on overflow.
+    fn checked_millis(self) -> Option<Duration> where Self: Sized {
+        Some(self.millis())
+    }
+    /// Like [`secs`](DurationHelper::secs), but returns `None` instead of wrapping on overflow.
+    fn checked_secs(self) -> Option<Duration> where Self: Sized {
+        Some(self.secs())
+    }
+    /// Like [`minutes`](DurationHelper::minutes), but returns `None` instead of wrapping on overflow.
+    fn checked_minutes(self) -> Option<Duration> where Self: Sized {
+        Some(self.minutes())
+    }
+    /// Like [`hours`](DurationHelper::hours), but returns `None` instead of wrapping on overflow.
+    fn checked_hours(self) -> Option<Duration> where Self: Sized {
+        Some(self.hours())
+    }
+    /// Like [`days`](DurationHelper::days), but returns `None` instead of wrapping on overflow.
+    fn checked_days(self) -> Option<Duration> where Self: Sized {
+        Some(self.days())
+    }
+    /// Like [`weeks`](DurationHelper::weeks), but returns `None` instead of wrapping on overflow.
+    fn checked_weeks(self) -> Option<Duration> where Self: Sized {
+        Some(self.weeks())
+    }
+    /// Like [`months`](DurationHelper::months), but returns `None` instead of wrapping on overflow.
+    fn checked_months(self) -> Option<Duration> where Self: Sized {
+        Some(self.months())
+    }
+    /// Like [`years`](DurationHelper::years), but returns `None` instead of wrapping on overflow.
+    fn checked_years(self) -> Option<Duration> where Self: Sized {
+        Some(self.years())
+    }
+
+    /// Like [`nanos`](DurationHelper::nanos), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_nanos(self) -> Duration where Self: Sized {
+        self.nanos()
+    }
+    /// Like [`micros`](DurationHelper::micros), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_micros(self) -> Duration where Self: Sized {
+        self.micros()
+    }
+    /// Like [`millis`](DurationHelper::millis), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_millis(self) -> Duration where Self: Sized {
+        self.millis()
+    }
+    /// Like [`secs`](DurationHelper::secs), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_secs(self) -> Duration where Self: Sized {
+        self.secs()
+    }
+    /// Like [`minutes`](DurationHelper::minutes), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_minutes(self) -> Duration where Self: Sized {
+        self.minutes()
+    }
+    /// Like [`hours`](DurationHelper::hours), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_hours(self) -> Duration where Self: Sized {
+        self.hours()
+    }
+    /// Like [`days`](DurationHelper::days), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_days(self) -> Duration where Self: Sized {
+        self.days()
+    }
+    /// Like [`weeks`](DurationHelper::weeks), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_weeks(self) -> Duration where Self: Sized {
+        self.weeks()
+    }
+    /// Like [`months`](DurationHelper::months), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_months(self) -> Duration where Self: Sized {
+        self.months()
+    }
+    /// Like [`years`](DurationHelper::years), but saturates to `Duration::MAX` instead of wrapping on overflow.
+    fn saturating_years(self) -> Duration where Self: Sized {
+        self.years()
+    }
 }
 
-impl DurationHelper for u64 {
-    fn nanos(self) -> Duration { Duration::from_nanos(self) }
-    fn micros(self) -> Duration { Duration::from_micros(self) }
-    fn millis(self) -> Duration { Duration::from_millis(self) }
-    fn secs(self) -> Duration { Duration::from_secs(self) }
-    fn hours(self) -> Duration { Duration::from_secs(60 * 60 * self) }
-    fn days(self) -> Duration { Duration::from_secs(60 * 60 * 24 * self) }
-    fn weeks(self) -> Duration { Duration::from_secs(60 * 60 * 24 * 7 * self) }
-    fn months(self) -> Duration { Duration::from_secs(60 * 60 * 24 * 30 * self) }
-    fn years(self) -> Duration { Duration::from_secs(60 * 60 * 24 * 365 * self) }
+macro_rules! impl_duration_helper_unsigned {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DurationHelper for $t {
+                fn nanos(self) -> Duration { Duration::from_nanos(self as u64) }
+                fn micros(self) -> Duration { Duration::from_micros(self as u64) }
+                fn millis(self) -> Duration { Duration::from_millis(self as u64) }
+                fn secs(self) -> Duration { Duration::from_secs(self as u64) }
+                fn minutes(self) -> Duration { Duration::from_secs(60 * self as u64) }
+                fn hours(self) -> Duration { Duration::from_secs(60 * 60 * self as u64) }
+                fn days(self) -> Duration { Duration::from_secs(60 * 60 * 24 * self as u64) }
+                fn weeks(self) -> Duration { Duration::from_secs(60 * 60 * 24 * 7 * self as u64) }
+                fn months(self) -> Duration { Duration::from_secs(60 * 60 * 24 * 30 * self as u64) }
+                fn years(self) -> Duration { Duration::from_secs(60 * 60 * 24 * 365 * self as u64) }
+
+                fn checked_minutes(self) -> Option<Duration> {
+                    (self as u64).checked_mul(60).map(Duration::from_secs)
+                }
+                fn checked_hours(self) -> Option<Duration> {
+                    (self as u64).checked_mul(60 * 60).map(Duration::from_secs)
+                }
+                fn checked_days(self) -> Option<Duration> {
+                    (self as u64).checked_mul(60 * 60 * 24).map(Duration::from_secs)
+                }
+                fn checked_weeks(self) -> Option<Duration> {
+                    (self as u64).checked_mul(60 * 60 * 24 * 7).map(Duration::from_secs)
+                }
+                fn checked_months(self) -> Option<Duration> {
+                    (self as u64).checked_mul(60 * 60 * 24 * 30).map(Duration::from_secs)
+                }
+                fn checked_years(self) -> Option<Duration> {
+                    (self as u64).checked_mul(60 * 60 * 24 * 365).map(Duration::from_secs)
+                }
+
+                fn saturating_minutes(self) -> Duration {
+                    self.checked_minutes().unwrap_or(Duration::MAX)
+                }
+                fn saturating_hours(self) -> Duration {
+                    self.checked_hours().unwrap_or(Duration::MAX)
+                }
+                fn saturating_days(self) -> Duration {
+                    self.checked_days().unwrap_or(Duration::MAX)
+                }
+                fn saturating_weeks(self) -> Duration {
+                    self.checked_weeks().unwrap_or(Duration::MAX)
+                }
+                fn saturating_months(self) -> Duration {
+                    self.checked_months().unwrap_or(Duration::MAX)
+                }
+                fn saturating_years(self) -> Duration {
+                    self.checked_years().unwrap_or(Duration::MAX)
+                }
+            }
+        )*
+    };
 }
 
-impl DurationHelper for f64 {
-    fn nanos(self) -> Duration { Duration::new(0, (self) as u32) }
-    fn micros(self) -> Duration { Duration::new(0, (self * 1_000.0) as u32) }
-    fn millis(self) -> Duration { Duration::new(0, (self * 1_000_000.0) as u32) }
-    fn secs(self) -> Duration { Duration::from_secs_f64(self) }
-    fn hours(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * self) }
-    fn days(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * self) }
-    fn weeks(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * 7.0 * self) }
-    fn months(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * 30.0 * self) }
-    fn years(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * 365.0 * self) }
+impl_duration_helper_unsigned!(u8, u16, u32, u64, usize);
+
+// Signed integers saturate to `Duration::ZERO` when negative, since `std::time::Duration`
+// has no representation for negative durations.
+macro_rules! impl_duration_helper_signed {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DurationHelper for $t {
+                fn nanos(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_nanos(self as u64)
+                }
+                fn micros(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_micros(self as u64)
+                }
+                fn millis(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_millis(self as u64)
+                }
+                fn secs(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(self as u64)
+                }
+                fn minutes(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(60 * self as u64)
+                }
+                fn hours(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(60 * 60 * self as u64)
+                }
+                fn days(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(60 * 60 * 24 * self as u64)
+                }
+                fn weeks(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(60 * 60 * 24 * 7 * self as u64)
+                }
+                fn months(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(60 * 60 * 24 * 30 * self as u64)
+                }
+                fn years(self) -> Duration {
+                    if self < 0 { return Duration::ZERO; }
+                    Duration::from_secs(60 * 60 * 24 * 365 * self as u64)
+                }
+
+                fn checked_minutes(self) -> Option<Duration> {
+                    if self < 0 { return Some(Duration::ZERO); }
+                    (self as u64).checked_mul(60).map(Duration::from_secs)
+                }
+                fn checked_hours(self) -> Option<Duration> {
+                    if self < 0 { return Some(Duration::ZERO); }
+                    (self as u64).checked_mul(60 * 60).map(Duration::from_secs)
+                }
+                fn checked_days(self) -> Option<Duration> {
+                    if self < 0 { return Some(Duration::ZERO); }
+                    (self as u64).checked_mul(60 * 60 * 24).map(Duration::from_secs)
+                }
+                fn checked_weeks(self) -> Option<Duration> {
+                    if self < 0 { return Some(Duration::ZERO); }
+                    (self as u64).checked_mul(60 * 60 * 24 * 7).map(Duration::from_secs)
+                }
+                fn checked_months(self) -> Option<Duration> {
+                    if self < 0 { return Some(Duration::ZERO); }
+                    (self as u64).checked_mul(60 * 60 * 24 * 30).map(Duration::from_secs)
+                }
+                fn checked_years(self) -> Option<Duration> {
+                    if self < 0 { return Some(Duration::ZERO); }
+                    (self as u64).checked_mul(60 * 60 * 24 * 365).map(Duration::from_secs)
+                }
+
+                fn saturating_minutes(self) -> Duration {
+                    self.checked_minutes().unwrap_or(Duration::MAX)
+                }
+                fn saturating_hours(self) -> Duration {
+                    self.checked_hours().unwrap_or(Duration::MAX)
+                }
+                fn saturating_days(self) -> Duration {
+                    self.checked_days().unwrap_or(Duration::MAX)
+                }
+                fn saturating_weeks(self) -> Duration {
+                    self.checked_weeks().unwrap_or(Duration::MAX)
+                }
+                fn saturating_months(self) -> Duration {
+                    self.checked_months().unwrap_or(Duration::MAX)
+                }
+                fn saturating_years(self) -> Duration {
+                    self.checked_years().unwrap_or(Duration::MAX)
+                }
+            }
+        )*
+    };
 }
 
+impl_duration_helper_signed!(i8, i16, i32, i64, isize);
+
+// `Duration::from_secs_f64` panics on negative/non-finite/too-large input, so the checked_*/
+// saturating_* overrides below route through this instead of the infallible constructors.
+// Negative inputs saturate to `Duration::ZERO`, mirroring the signed-integer impls.
+fn checked_duration_from_secs_f64(secs: f64) -> Option<Duration> {
+    if secs < 0.0 {
+        Some(Duration::ZERO)
+    } else {
+        Duration::try_from_secs_f64(secs).ok()
+    }
+}
+
+macro_rules! impl_duration_helper_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl DurationHelper for $t {
+                fn nanos(self) -> Duration { Duration::from_secs_f64(self as f64 / 1_000_000_000.0) }
+                fn micros(self) -> Duration { Duration::from_secs_f64(self as f64 / 1_000_000.0) }
+                fn millis(self) -> Duration { Duration::from_secs_f64(self as f64 / 1_000.0) }
+                fn secs(self) -> Duration { Duration::from_secs_f64(self as f64) }
+                fn minutes(self) -> Duration { Duration::from_secs_f64(60.0 * self as f64) }
+                fn hours(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * self as f64) }
+                fn days(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * self as f64) }
+                fn weeks(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * 7.0 * self as f64) }
+                fn months(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * 30.0 * self as f64) }
+                fn years(self) -> Duration { Duration::from_secs_f64(60.0 * 60.0 * 24.0 * 365.0 * self as f64) }
+
+                fn checked_nanos(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(self as f64 / 1_000_000_000.0)
+                }
+                fn checked_micros(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(self as f64 / 1_000_000.0)
+                }
+                fn checked_millis(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(self as f64 / 1_000.0)
+                }
+                fn checked_secs(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(self as f64)
+                }
+                fn checked_minutes(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(60.0 * self as f64)
+                }
+                fn checked_hours(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(60.0 * 60.0 * self as f64)
+                }
+                fn checked_days(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(60.0 * 60.0 * 24.0 * self as f64)
+                }
+                fn checked_weeks(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(60.0 * 60.0 * 24.0 * 7.0 * self as f64)
+                }
+                fn checked_months(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(60.0 * 60.0 * 24.0 * 30.0 * self as f64)
+                }
+                fn checked_years(self) -> Option<Duration> {
+                    checked_duration_from_secs_f64(60.0 * 60.0 * 24.0 * 365.0 * self as f64)
+                }
+
+                fn saturating_nanos(self) -> Duration {
+                    self.checked_nanos().unwrap_or(Duration::MAX)
+                }
+                fn saturating_micros(self) -> Duration {
+                    self.checked_micros().unwrap_or(Duration::MAX)
+                }
+                fn saturating_millis(self) -> Duration {
+                    self.checked_millis().unwrap_or(Duration::MAX)
+                }
+                fn saturating_secs(self) -> Duration {
+                    self.checked_secs().unwrap_or(Duration::MAX)
+                }
+                fn saturating_minutes(self) -> Duration {
+                    self.checked_minutes().unwrap_or(Duration::MAX)
+                }
+                fn saturating_hours(self) -> Duration {
+                    self.checked_hours().unwrap_or(Duration::MAX)
+                }
+                fn saturating_days(self) -> Duration {
+                    self.checked_days().unwrap_or(Duration::MAX)
+                }
+                fn saturating_weeks(self) -> Duration {
+                    self.checked_weeks().unwrap_or(Duration::MAX)
+                }
+                fn saturating_months(self) -> Duration {
+                    self.checked_months().unwrap_or(Duration::MAX)
+                }
+                fn saturating_years(self) -> Duration {
+                    self.checked_years().unwrap_or(Duration::MAX)
+                }
+            }
+        )*
+    };
+}
+
+impl_duration_helper_float!(f32, f64);
+
 
 #[cfg(test)]
 mod tests {
@@ -69,9 +401,9 @@ mod tests {
 
     #[test]
     fn test_nanos() {
-        assert_eq!(0.5.nanos(), Duration::from_nanos(0));  // f64: It's less than 1 nanosecond.
-        assert_eq!(3.5.nanos(), Duration::from_nanos(3)); 
-        assert_eq!(5.nanos(), Duration::from_nanos(5)); 
+        assert_eq!(0.5.nanos(), Duration::from_nanos(1));  // f64: rounds to the nearest nanosecond.
+        assert_eq!(3.5.nanos(), Duration::from_nanos(3));
+        assert_eq!(5.nanos(), Duration::from_nanos(5));
     }
 
     #[test]
@@ -86,12 +418,27 @@ mod tests {
         assert_eq!(5.millis(), Duration::from_millis(5));
     }
 
+    #[test]
+    fn test_large_magnitude_float_sub_second_constructors() {
+        // Previously these truncated the whole-seconds part and overflowed the u32 nanos field.
+        assert_eq!(5000.0.millis(), Duration::from_secs(5));
+        assert_eq!(2_000_000_000.0.nanos(), Duration::from_secs(2));
+        assert_eq!(1_500_000.0.micros(), Duration::from_millis(1500));
+    }
+
     #[test]
     fn test_secs() {
         assert_eq!(0.5.secs(), Duration::from_millis(500));
         assert_eq!(5.secs(), Duration::from_secs(5));
     }
 
+    #[test]
+    fn test_minutes() {
+        assert_eq!(0.5.minutes(), Duration::from_secs(30));
+        assert_eq!(5.minutes(), Duration::from_secs(5 * 60));
+        assert_eq!(5u32.minutes(), Duration::from_secs(5 * 60));
+    }
+
     #[test]
     fn test_hours() {
         assert_eq!(0.5.hours(), Duration::from_secs(30 * 60));
@@ -113,7 +460,7 @@ mod tests {
     #[test]
     fn test_months() {
         assert_eq!(0.5.months(), Duration::from_secs(15 * 24 * 60 * 60));
-        assert_eq!(5.months(), Duration::from_secs(5 * 30 * 24 * 60 * 60)); 
+        assert_eq!(5.months(), Duration::from_secs(5 * 30 * 24 * 60 * 60));
     }
 
     #[test]
@@ -121,4 +468,59 @@ mod tests {
         assert_eq!(0.5.years(), Duration::from_secs(182 * 24 * 60 * 60 + 12 * 60 * 60));
         assert_eq!(5.years(), Duration::from_secs(5 * 365 * 24 * 60 * 60));
     }
+
+    #[test]
+    fn test_all_primitive_types_implement_duration_helper() {
+        assert_eq!(1u8.secs(), Duration::from_secs(1));
+        assert_eq!(1u16.secs(), Duration::from_secs(1));
+        assert_eq!(1u32.secs(), Duration::from_secs(1));
+        assert_eq!(1u64.secs(), Duration::from_secs(1));
+        assert_eq!(1usize.secs(), Duration::from_secs(1));
+        assert_eq!(1i8.secs(), Duration::from_secs(1));
+        assert_eq!(1i16.secs(), Duration::from_secs(1));
+        assert_eq!(1i32.secs(), Duration::from_secs(1));
+        assert_eq!(1i64.secs(), Duration::from_secs(1));
+        assert_eq!(1isize.secs(), Duration::from_secs(1));
+        assert_eq!(1.0f32.secs(), Duration::from_secs(1));
+        assert_eq!(1.0f64.secs(), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_negative_signed_saturates_to_zero() {
+        assert_eq!((-5i32).secs(), Duration::ZERO);
+        assert_eq!((-1i64).years(), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_checked_years_overflow() {
+        assert_eq!(5u64.checked_years(), Some(Duration::from_secs(5 * 365 * 24 * 60 * 60)));
+        assert_eq!(u64::MAX.checked_years(), None);
+    }
+
+    #[test]
+    fn test_saturating_years_overflow() {
+        assert_eq!(5u64.saturating_years(), Duration::from_secs(5 * 365 * 24 * 60 * 60));
+        assert_eq!(u64::MAX.saturating_years(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_checked_and_saturating_with_negative_signed() {
+        assert_eq!((-5i32).checked_hours(), Some(Duration::ZERO));
+        assert_eq!((-5i32).saturating_hours(), Duration::ZERO);
+        assert_eq!(i64::MAX.checked_years(), None);
+        assert_eq!(i64::MAX.saturating_years(), Duration::MAX);
+    }
+
+    #[test]
+    fn test_checked_and_saturating_years_with_huge_float() {
+        assert_eq!(1e20f64.checked_years(), None);
+        assert_eq!(1e20f64.saturating_years(), Duration::MAX);
+        assert_eq!(5.0f64.checked_years(), Some(Duration::from_secs(5 * 365 * 24 * 60 * 60)));
+    }
+
+    #[test]
+    fn test_checked_and_saturating_with_negative_float() {
+        assert_eq!((-5.0f64).checked_hours(), Some(Duration::ZERO));
+        assert_eq!((-5.0f64).saturating_hours(), Duration::ZERO);
+    }
 }
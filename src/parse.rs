@@ -0,0 +1,192 @@
+//! The inverse of [`DurationHelper`](crate::DurationHelper): turning human-written strings like
+//! `"1h30m"` or `"0.5d"` into a `Duration`, and back again.
+
+use crate::DurationHelper;
+use std::fmt;
+use std::time::Duration;
+
+/// An error produced by [`parse_duration`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+    /// A component was missing its numeric value, e.g. a bare unit.
+    InvalidNumber(String),
+    /// A component had a number but no unit suffix.
+    MissingUnit(String),
+    /// A unit suffix wasn't one of `ns`, `us`, `ms`, `s`, `m`/`min`, `h`, `d`, `w`, `mo`, `y`.
+    UnknownUnit(String),
+    /// Accumulating the parsed components overflowed `Duration`.
+    Overflow,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "duration string was empty"),
+            ParseError::InvalidNumber(s) => write!(f, "invalid number in duration string: {s:?}"),
+            ParseError::MissingUnit(s) => write!(f, "missing unit after number {s:?}"),
+            ParseError::UnknownUnit(s) => write!(f, "unknown duration unit {s:?}"),
+            ParseError::Overflow => write!(f, "duration string overflowed Duration"),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses a human-readable duration such as `"1h30m"` or `"0.5d"` into a [`Duration`].
+///
+/// Understands the same unit vocabulary as [`DurationHelper`](crate::DurationHelper):
+/// `ns`, `us`, `ms`, `s`, `m`/`min`, `h`, `d`, `w`, `mo`, `y`. Components are summed, so
+/// compound inputs like `"1h30m"` work, and each unit may be fractional, e.g. `"0.5d"`.
+///
+/// Months are assumed to be 30 days and years 365 days, matching the rest of the crate.
+pub fn parse_duration(input: &str) -> Result<Duration, ParseError> {
+    if input.trim().is_empty() {
+        return Err(ParseError::Empty);
+    }
+
+    let mut total = Duration::ZERO;
+    let bytes = input.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        let number_start = i;
+        let mut seen_dot = false;
+        while i < bytes.len() && (bytes[i].is_ascii_digit() || (bytes[i] == b'.' && !seen_dot)) {
+            seen_dot |= bytes[i] == b'.';
+            i += 1;
+        }
+        if i == number_start {
+            return Err(ParseError::InvalidNumber(input[number_start..].to_string()));
+        }
+        let number_str = &input[number_start..i];
+        let value: f64 = number_str
+            .parse()
+            .map_err(|_| ParseError::InvalidNumber(number_str.to_string()))?;
+
+        let unit_start = i;
+        while i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+            i += 1;
+        }
+        if i == unit_start {
+            return Err(ParseError::MissingUnit(number_str.to_string()));
+        }
+        let unit = &input[unit_start..i];
+
+        let component = duration_for_unit(value, unit)?;
+        total = total.checked_add(component).ok_or(ParseError::Overflow)?;
+    }
+
+    Ok(total)
+}
+
+fn duration_for_unit(value: f64, unit: &str) -> Result<Duration, ParseError> {
+    // Delegates to `DurationHelper`'s own checked_* accessors rather than re-deriving the
+    // unit multipliers here, so parsing uses the trait's own month/year definitions.
+    let component = match unit {
+        "ns" => value.checked_nanos(),
+        "us" => value.checked_micros(),
+        "ms" => value.checked_millis(),
+        "s" => value.checked_secs(),
+        "m" | "min" => value.checked_minutes(),
+        "h" => value.checked_hours(),
+        "d" => value.checked_days(),
+        "w" => value.checked_weeks(),
+        "mo" => value.checked_months(),
+        "y" => value.checked_years(),
+        other => return Err(ParseError::UnknownUnit(other.to_string())),
+    };
+    component.ok_or(ParseError::Overflow)
+}
+
+const HUMANIZE_UNITS: [(u128, &str); 10] = [
+    (365 * 24 * 60 * 60 * 1_000_000_000, "y"),
+    (30 * 24 * 60 * 60 * 1_000_000_000, "mo"),
+    (7 * 24 * 60 * 60 * 1_000_000_000, "w"),
+    (24 * 60 * 60 * 1_000_000_000, "d"),
+    (60 * 60 * 1_000_000_000, "h"),
+    (60 * 1_000_000_000, "m"),
+    (1_000_000_000, "s"),
+    (1_000_000, "ms"),
+    (1_000, "us"),
+    (1, "ns"),
+];
+
+/// Formats a [`Duration`] as a compact human-readable string, e.g. `Duration::from_secs(5400)`
+/// becomes `"1h30m"`.
+///
+/// Decomposes the duration greedily from the largest unit (`y`) down to the smallest (`ns`),
+/// emitting only the non-zero components. A zero duration is formatted as `"0s"`.
+pub fn humanize(duration: Duration) -> String {
+    let mut remaining = duration.as_nanos();
+    let mut out = String::new();
+
+    for (unit_nanos, suffix) in HUMANIZE_UNITS {
+        let count = remaining / unit_nanos;
+        if count > 0 {
+            out.push_str(&count.to_string());
+            out.push_str(suffix);
+            remaining %= unit_nanos;
+        }
+    }
+
+    if out.is_empty() {
+        "0s".to_string()
+    } else {
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_single_unit() {
+        assert_eq!(parse_duration("5s").unwrap(), Duration::from_secs(5));
+        assert_eq!(parse_duration("2h").unwrap(), Duration::from_secs(2 * 60 * 60));
+        assert_eq!(parse_duration("3min").unwrap(), Duration::from_secs(3 * 60));
+    }
+
+    #[test]
+    fn test_parse_fractional_unit() {
+        assert_eq!(parse_duration("0.5d").unwrap(), Duration::from_secs(12 * 60 * 60));
+    }
+
+    #[test]
+    fn test_parse_compound_input() {
+        assert_eq!(
+            parse_duration("1h30m").unwrap(),
+            Duration::from_secs(60 * 60 + 30 * 60)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse_duration(""), Err(ParseError::Empty));
+        assert_eq!(parse_duration("5"), Err(ParseError::MissingUnit("5".to_string())));
+        assert_eq!(parse_duration("h"), Err(ParseError::InvalidNumber("h".to_string())));
+        assert_eq!(parse_duration("5zz"), Err(ParseError::UnknownUnit("zz".to_string())));
+    }
+
+    #[test]
+    fn test_humanize_decomposes_non_zero_components() {
+        assert_eq!(humanize(Duration::from_secs(60 * 60 + 30 * 60)), "1h30m");
+        assert_eq!(humanize(Duration::from_secs(5)), "5s");
+        assert_eq!(humanize(Duration::ZERO), "0s");
+    }
+
+    #[test]
+    fn test_parse_overflow_does_not_panic() {
+        assert_eq!(parse_duration("999999999999999y"), Err(ParseError::Overflow));
+        let huge = format!("{}s", "9".repeat(305));
+        assert_eq!(parse_duration(&huge), Err(ParseError::Overflow));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let original = Duration::from_secs(5 * 365 * 24 * 60 * 60 + 60 * 60 + 30 * 60 + 5);
+        let roundtripped = parse_duration(&humanize(original)).unwrap();
+        assert_eq!(roundtripped, original);
+    }
+}
@@ -0,0 +1,251 @@
+//! A signed companion to [`DurationHelper`](crate::DurationHelper).
+//!
+//! `std::time::Duration` can't represent negative spans, so expressions like `(-5).secs()`
+//! have nowhere to put their sign. [`SignedDuration`] and [`SignedDurationHelper`] fill that
+//! gap, mirroring the `time` crate's `NumericalDuration` support for signed integers.
+
+use crate::sealed;
+use std::convert::TryFrom;
+use std::fmt;
+use std::ops::{Add, Neg, Sub};
+use std::time::Duration;
+
+/// A `Duration` paired with a sign, so arithmetic that temporarily goes negative
+/// (e.g. "5 minutes ago") has somewhere to live.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SignedDuration {
+    negative: bool,
+    abs: Duration,
+}
+
+impl SignedDuration {
+    pub const ZERO: SignedDuration = SignedDuration {
+        negative: false,
+        abs: Duration::ZERO,
+    };
+
+    /// Builds a `SignedDuration` from a sign and a magnitude. A zero magnitude is always
+    /// normalized to non-negative, so `SignedDuration::ZERO` is the only representation of zero.
+    pub fn new(negative: bool, abs: Duration) -> Self {
+        SignedDuration {
+            negative: negative && !abs.is_zero(),
+            abs,
+        }
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    pub fn abs(&self) -> Duration {
+        self.abs
+    }
+
+    /// Builds a `SignedDuration` from a (possibly negative) floating point number of seconds.
+    pub fn from_signed_secs_f64(secs: f64) -> Self {
+        if secs < 0.0 {
+            SignedDuration::new(true, Duration::from_secs_f64(-secs))
+        } else {
+            SignedDuration::new(false, Duration::from_secs_f64(secs))
+        }
+    }
+}
+
+impl Neg for SignedDuration {
+    type Output = SignedDuration;
+
+    fn neg(self) -> SignedDuration {
+        SignedDuration::new(!self.negative, self.abs)
+    }
+}
+
+impl Add for SignedDuration {
+    type Output = SignedDuration;
+
+    fn add(self, rhs: SignedDuration) -> SignedDuration {
+        match (self.negative, rhs.negative) {
+            (false, false) => SignedDuration::new(false, self.abs + rhs.abs),
+            (true, true) => SignedDuration::new(true, self.abs + rhs.abs),
+            (false, true) if self.abs >= rhs.abs => SignedDuration::new(false, self.abs - rhs.abs),
+            (false, true) => SignedDuration::new(true, rhs.abs - self.abs),
+            (true, false) if rhs.abs >= self.abs => SignedDuration::new(false, rhs.abs - self.abs),
+            (true, false) => SignedDuration::new(true, self.abs - rhs.abs),
+        }
+    }
+}
+
+impl Sub for SignedDuration {
+    type Output = SignedDuration;
+
+    fn sub(self, rhs: SignedDuration) -> SignedDuration {
+        self + (-rhs)
+    }
+}
+
+/// The error returned when converting a negative [`SignedDuration`] into a `Duration`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TryFromSignedDurationError;
+
+impl fmt::Display for TryFromSignedDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "cannot convert a negative SignedDuration into a Duration")
+    }
+}
+
+impl std::error::Error for TryFromSignedDurationError {}
+
+impl TryFrom<SignedDuration> for Duration {
+    type Error = TryFromSignedDurationError;
+
+    fn try_from(value: SignedDuration) -> Result<Duration, Self::Error> {
+        if value.negative {
+            Err(TryFromSignedDurationError)
+        } else {
+            Ok(value.abs)
+        }
+    }
+}
+
+pub trait SignedDurationHelper: sealed::Sealed {
+    fn nanos_signed(self) -> SignedDuration;
+    fn micros_signed(self) -> SignedDuration;
+    fn millis_signed(self) -> SignedDuration;
+    fn secs_signed(self) -> SignedDuration;
+    fn minutes_signed(self) -> SignedDuration;
+    fn hours_signed(self) -> SignedDuration;
+    fn days_signed(self) -> SignedDuration;
+    fn weeks_signed(self) -> SignedDuration;
+    /// Assuming a month is 30 days
+    fn months_signed(self) -> SignedDuration;
+    /// Assuming a year is 365 days
+    fn years_signed(self) -> SignedDuration;
+}
+
+// `self * multiplier` seconds can overflow `u64` for large inputs (e.g. `u64::MAX.years_signed()`),
+// so the multiplied units below route through this instead of panicking, saturating to
+// `Duration::MAX` on overflow rather than wrapping.
+fn saturating_secs_duration(magnitude: u64, multiplier: u64) -> Duration {
+    magnitude
+        .checked_mul(multiplier)
+        .map(Duration::from_secs)
+        .unwrap_or(Duration::MAX)
+}
+
+macro_rules! impl_signed_duration_helper_unsigned {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SignedDurationHelper for $t {
+                fn nanos_signed(self) -> SignedDuration { SignedDuration::new(false, Duration::from_nanos(self as u64)) }
+                fn micros_signed(self) -> SignedDuration { SignedDuration::new(false, Duration::from_micros(self as u64)) }
+                fn millis_signed(self) -> SignedDuration { SignedDuration::new(false, Duration::from_millis(self as u64)) }
+                fn secs_signed(self) -> SignedDuration { SignedDuration::new(false, Duration::from_secs(self as u64)) }
+                fn minutes_signed(self) -> SignedDuration { SignedDuration::new(false, saturating_secs_duration(self as u64, 60)) }
+                fn hours_signed(self) -> SignedDuration { SignedDuration::new(false, saturating_secs_duration(self as u64, 60 * 60)) }
+                fn days_signed(self) -> SignedDuration { SignedDuration::new(false, saturating_secs_duration(self as u64, 60 * 60 * 24)) }
+                fn weeks_signed(self) -> SignedDuration { SignedDuration::new(false, saturating_secs_duration(self as u64, 60 * 60 * 24 * 7)) }
+                fn months_signed(self) -> SignedDuration { SignedDuration::new(false, saturating_secs_duration(self as u64, 60 * 60 * 24 * 30)) }
+                fn years_signed(self) -> SignedDuration { SignedDuration::new(false, saturating_secs_duration(self as u64, 60 * 60 * 24 * 365)) }
+            }
+        )*
+    };
+}
+
+impl_signed_duration_helper_unsigned!(u8, u16, u32, u64, usize);
+
+macro_rules! impl_signed_duration_helper_signed {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SignedDurationHelper for $t {
+                fn nanos_signed(self) -> SignedDuration { SignedDuration::new(self < 0, Duration::from_nanos(self.unsigned_abs() as u64)) }
+                fn micros_signed(self) -> SignedDuration { SignedDuration::new(self < 0, Duration::from_micros(self.unsigned_abs() as u64)) }
+                fn millis_signed(self) -> SignedDuration { SignedDuration::new(self < 0, Duration::from_millis(self.unsigned_abs() as u64)) }
+                fn secs_signed(self) -> SignedDuration { SignedDuration::new(self < 0, Duration::from_secs(self.unsigned_abs() as u64)) }
+                fn minutes_signed(self) -> SignedDuration { SignedDuration::new(self < 0, saturating_secs_duration(self.unsigned_abs() as u64, 60)) }
+                fn hours_signed(self) -> SignedDuration { SignedDuration::new(self < 0, saturating_secs_duration(self.unsigned_abs() as u64, 60 * 60)) }
+                fn days_signed(self) -> SignedDuration { SignedDuration::new(self < 0, saturating_secs_duration(self.unsigned_abs() as u64, 60 * 60 * 24)) }
+                fn weeks_signed(self) -> SignedDuration { SignedDuration::new(self < 0, saturating_secs_duration(self.unsigned_abs() as u64, 60 * 60 * 24 * 7)) }
+                fn months_signed(self) -> SignedDuration { SignedDuration::new(self < 0, saturating_secs_duration(self.unsigned_abs() as u64, 60 * 60 * 24 * 30)) }
+                fn years_signed(self) -> SignedDuration { SignedDuration::new(self < 0, saturating_secs_duration(self.unsigned_abs() as u64, 60 * 60 * 24 * 365)) }
+            }
+        )*
+    };
+}
+
+impl_signed_duration_helper_signed!(i8, i16, i32, i64, isize);
+
+macro_rules! impl_signed_duration_helper_float {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl SignedDurationHelper for $t {
+                fn nanos_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(self as f64 / 1_000_000_000.0) }
+                fn micros_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(self as f64 / 1_000_000.0) }
+                fn millis_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(self as f64 / 1_000.0) }
+                fn secs_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(self as f64) }
+                fn minutes_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(60.0 * self as f64) }
+                fn hours_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(60.0 * 60.0 * self as f64) }
+                fn days_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(60.0 * 60.0 * 24.0 * self as f64) }
+                fn weeks_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(60.0 * 60.0 * 24.0 * 7.0 * self as f64) }
+                fn months_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(60.0 * 60.0 * 24.0 * 30.0 * self as f64) }
+                fn years_signed(self) -> SignedDuration { SignedDuration::from_signed_secs_f64(60.0 * 60.0 * 24.0 * 365.0 * self as f64) }
+            }
+        )*
+    };
+}
+
+impl_signed_duration_helper_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SignedDurationHelper;
+
+    #[test]
+    fn test_secs_signed() {
+        assert_eq!(5i64.secs_signed(), SignedDuration::new(false, Duration::from_secs(5)));
+        assert_eq!((-5i64).secs_signed(), SignedDuration::new(true, Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn test_neg() {
+        let five_secs = 5i64.secs_signed();
+        assert_eq!(-five_secs, SignedDuration::new(true, Duration::from_secs(5)));
+        assert_eq!(-(-five_secs), five_secs);
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let three = 3i64.secs_signed();
+        let five = 5i64.secs_signed();
+        assert_eq!(three + five, SignedDuration::new(false, Duration::from_secs(8)));
+        assert_eq!(three - five, SignedDuration::new(true, Duration::from_secs(2)));
+        assert_eq!(five - three, SignedDuration::new(false, Duration::from_secs(2)));
+        assert_eq!(-three + -five, SignedDuration::new(true, Duration::from_secs(8)));
+    }
+
+    #[test]
+    fn test_try_into_duration() {
+        let positive = 5i64.secs_signed();
+        let negative = (-5i64).secs_signed();
+        assert_eq!(Duration::try_from(positive), Ok(Duration::from_secs(5)));
+        assert!(Duration::try_from(negative).is_err());
+    }
+
+    #[test]
+    fn test_from_signed_secs_f64() {
+        assert_eq!(SignedDuration::from_signed_secs_f64(-0.5), SignedDuration::new(true, Duration::from_millis(500)));
+        assert_eq!(SignedDuration::from_signed_secs_f64(1.5), SignedDuration::new(false, Duration::from_millis(1500)));
+    }
+
+    #[test]
+    fn test_zero_is_never_negative() {
+        assert_eq!(SignedDuration::new(true, Duration::ZERO), SignedDuration::ZERO);
+        assert!(!SignedDuration::new(true, Duration::ZERO).is_negative());
+    }
+
+    #[test]
+    fn test_overflow_saturates_instead_of_panicking() {
+        assert_eq!(u64::MAX.years_signed(), SignedDuration::new(false, Duration::MAX));
+        assert_eq!(i64::MIN.years_signed(), SignedDuration::new(true, Duration::MAX));
+        assert_eq!(isize::MIN.months_signed(), SignedDuration::new(true, Duration::MAX));
+    }
+}